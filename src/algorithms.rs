@@ -0,0 +1,342 @@
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine};
+use ark_ff::PrimeField;
+use ark_poly::{univariate::DensePolynomial, UVPolynomial};
+use ark_std::{UniformRand, Zero};
+
+use crate::data_structures::{Commitment, CommitmentKey, HidingKey, Proof};
+
+/// The ILV inner-product commitment scheme.
+///
+/// A commitment to `a = (a_1, .., a_n)` is `C = g^{A(beta)}` with `A(X) = sum_i a_i X^i`.
+/// To prove `<a, b> = v` for a public `b`, the prover builds the "reversed" polynomial
+/// `B(X) = sum_j b_j X^{n+1-j}`; the coefficient of `X^{n+1}` in `A(X) * B(X)` is then
+/// exactly `<a, b>`, so a witness to that single coefficient certifies the claim.
+///
+/// See <http://www.lsv.fr/Publis/PAPERS/PDF/ILV-imacc11-long.pdf>.
+pub struct ILV;
+
+fn scalars<F: PrimeField>(coeffs: &[F]) -> Vec<F::BigInt> {
+    coeffs.iter().map(|c| c.into_repr()).collect()
+}
+
+impl ILV {
+    /// Commits to `a` as `C = g^{A(beta)}`.
+    pub fn commit<E: PairingEngine>(ck: &CommitmentKey<E>, a: &[E::Fr]) -> Commitment<E> {
+        Commitment(Self::commit_a(ck, a).into())
+    }
+
+    /// Proves that the vector `a` (as committed to by [`commit`](Self::commit)) has inner
+    /// product `v` with the public vector `b`.
+    ///
+    /// `a` and `b` must both have length exactly `ck.powers_of_beta_g_first.len() - 1`
+    /// (the SRS's supported dimension) — see [`witness`](Self::witness) for why a shorter
+    /// `a` isn't (yet) supported.
+    pub fn open<E: PairingEngine>(
+        ck: &CommitmentKey<E>,
+        a: &[E::Fr],
+        b: &[E::Fr],
+        v: E::Fr,
+    ) -> Proof<E> {
+        Proof(Self::witness(ck, a, b, v).into())
+    }
+
+    /// Verifies a proof that the vector committed to in `commitment` has inner product `v`
+    /// with the public vector `b`.
+    ///
+    /// Checks `e(C, g_B) == e(W, h) * e(g, h^{beta^{n+1}})^v`, where `g_B = sum_j b_j *
+    /// h^{beta^{n+1-j}}` and the `beta^{n+1}` term is only ever supplied on the `h`/G2
+    /// side, via `powers_of_beta_h` — never in G1.
+    pub fn verify<E: PairingEngine>(
+        ck: &CommitmentKey<E>,
+        commitment: &Commitment<E>,
+        b: &[E::Fr],
+        v: E::Fr,
+        proof: &Proof<E>,
+    ) -> bool {
+        let n = b.len();
+        let g_b = Self::reversed_b_g2(ck, b);
+        let g = ck.powers_of_beta_g_first[0];
+        let h = ck.powers_of_beta_h[0];
+        let h_beta_np1 = ck.powers_of_beta_h[n + 1];
+
+        E::pairing(commitment.0, g_b)
+            == E::pairing(proof.0, h) * E::pairing(g, h_beta_np1).pow(v.into_repr())
+    }
+
+    /// Hiding variant of [`commit`](Self::commit): blinds `C` with `hk.gamma_g` so that
+    /// neither the commitment nor a proof opened against it leak anything about `a`, not
+    /// even the zero vector's otherwise-conspicuous identity commitment. Returns the
+    /// sampled blinding factor, which must be supplied to [`open_hiding`](Self::open_hiding).
+    pub fn commit_hiding<E: PairingEngine, R: ark_std::rand::Rng>(
+        ck: &CommitmentKey<E>,
+        hk: &HidingKey<E>,
+        a: &[E::Fr],
+        rng: &mut R,
+    ) -> (Commitment<E>, E::Fr) {
+        let r = E::Fr::rand(rng);
+        let blinded = Self::commit_a(ck, a) + hk.gamma_g.mul(r);
+        (Commitment(blinded.into()), r)
+    }
+
+    /// Hiding variant of [`open`](Self::open): blinds the witness with the same factor `r`
+    /// used in `commit_hiding`, via the `gamma`-scaled powers in `hk.powers_of_gamma_g`. The
+    /// verification equation in [`verify`](Self::verify) is unchanged — the blinding term
+    /// cancels out of the pairing check on its own.
+    pub fn open_hiding<E: PairingEngine>(
+        ck: &CommitmentKey<E>,
+        hk: &HidingKey<E>,
+        a: &[E::Fr],
+        b: &[E::Fr],
+        v: E::Fr,
+        r: E::Fr,
+    ) -> Proof<E> {
+        let bases = &hk.powers_of_gamma_g[1..=b.len()];
+        let scalars = Self::reversed_scalars(b);
+        let blind = VariableBaseMSM::multi_scalar_mul(bases, &scalars).mul(r.into_repr());
+
+        Proof((Self::witness(ck, a, b, v) + blind).into())
+    }
+
+    /// Batch-proves a single commitment's inner product against many public claims
+    /// `(b_1, v_1), .., (b_k, v_k)` as one aggregated witness instead of `k` separate
+    /// proofs.
+    ///
+    /// Derives a Fiat-Shamir challenge `rho` from `commitment` and the claims, then opens
+    /// the single combined claim `(sum_i rho^i b_i, sum_i rho^i v_i)`.
+    pub fn prove_batch<E: PairingEngine>(
+        ck: &CommitmentKey<E>,
+        a: &[E::Fr],
+        commitment: &Commitment<E>,
+        claims: &[(&[E::Fr], E::Fr)],
+    ) -> Proof<E> {
+        let (b_star, v_star) = Self::batch_combine(commitment, claims);
+        Self::open(ck, a, &b_star, v_star)
+    }
+
+    /// Verifies a proof produced by [`prove_batch`](Self::prove_batch) against the same
+    /// `commitment` and `claims`, collapsing what would be `k` pairings into a constant
+    /// number.
+    pub fn verify_batch<E: PairingEngine>(
+        ck: &CommitmentKey<E>,
+        commitment: &Commitment<E>,
+        claims: &[(&[E::Fr], E::Fr)],
+        proof: &Proof<E>,
+    ) -> bool {
+        let (b_star, v_star) = Self::batch_combine(commitment, claims);
+        Self::verify(ck, commitment, &b_star, v_star, proof)
+    }
+
+    /// Combines `claims` into the single `(b*, v*) = (sum_i rho^i b_i, sum_i rho^i v_i)`
+    /// checked by both `prove_batch` and `verify_batch`.
+    fn batch_combine<E: PairingEngine>(
+        commitment: &Commitment<E>,
+        claims: &[(&[E::Fr], E::Fr)],
+    ) -> (Vec<E::Fr>, E::Fr) {
+        let rho = Self::batch_challenge(commitment, claims);
+        let n = claims.iter().map(|(b, _)| b.len()).max().unwrap_or(0);
+
+        let mut rho_i = E::Fr::one();
+        let mut b_star = vec![E::Fr::zero(); n];
+        let mut v_star = E::Fr::zero();
+        for (b, v) in claims {
+            for (b_star_j, b_j) in b_star.iter_mut().zip(b.iter()) {
+                *b_star_j += rho_i * b_j;
+            }
+            v_star += rho_i * v;
+            rho_i *= rho;
+        }
+
+        (b_star, v_star)
+    }
+
+    /// Derives the Fiat-Shamir challenge `rho` used by `prove_batch`/`verify_batch` by
+    /// hashing the commitment and every claim.
+    fn batch_challenge<E: PairingEngine>(
+        commitment: &Commitment<E>,
+        claims: &[(&[E::Fr], E::Fr)],
+    ) -> E::Fr {
+        use ark_serialize::CanonicalSerialize;
+        use blake2::{Blake2s256, Digest};
+
+        let mut bytes = Vec::new();
+        commitment.0.serialize(&mut bytes).unwrap();
+        for (b, v) in claims {
+            for b_j in b.iter() {
+                b_j.serialize(&mut bytes).unwrap();
+            }
+            v.serialize(&mut bytes).unwrap();
+        }
+
+        E::Fr::from_le_bytes_mod_order(&Blake2s256::digest(&bytes))
+    }
+
+    fn commit_a<E: PairingEngine>(ck: &CommitmentKey<E>, a: &[E::Fr]) -> E::G1Projective {
+        let bases = &ck.powers_of_beta_g_first[1..=a.len()];
+        let scalars = a.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+    }
+
+    /// Computes `g^{W(beta)}` for `W(X) = A(X) * B(X) - v * X^{n+1}`, a degree-`2n`
+    /// polynomial, by splitting its coefficients across `powers_of_beta_g_first` (degrees
+    /// `0..=n`) and `powers_of_beta_g_second` (degrees `n+2..=2n`) and dropping the
+    /// `X^{n+1}` coefficient entirely rather than evaluating it against a G1 power.
+    ///
+    /// That coefficient is `<a, b> - v`, which is zero exactly when the caller's claimed
+    /// `v` is correct — dropping it changes nothing for an honest proof, and for a false
+    /// `v` it simply makes the witness (and so `verify`) wrong rather than handing out
+    /// `g^{beta^{n+1}}` for an attacker to exploit the way `attack` does.
+    ///
+    /// Because `powers_of_beta_g_second` only covers `n+2..=2n` relative to the SRS's own
+    /// `dim` (it has no way to "re-center" itself per call), this requires `a.len() ==
+    /// ck.powers_of_beta_g_first.len() - 1`, i.e. vectors of exactly the SRS's supported
+    /// dimension, not merely up to it.
+    fn witness<E: PairingEngine>(
+        ck: &CommitmentKey<E>,
+        a: &[E::Fr],
+        b: &[E::Fr],
+        v: E::Fr,
+    ) -> E::G1Projective {
+        assert_eq!(a.len(), b.len());
+        let n = a.len();
+        assert_eq!(
+            n + 1,
+            ck.powers_of_beta_g_first.len(),
+            "open/verify require vectors of exactly the SRS's supported dimension"
+        );
+
+        let a_poly = Self::a_poly(a);
+        let b_poly = Self::reversed_b_poly(b);
+        let mut w_coeffs = (&a_poly * &b_poly).coeffs;
+        w_coeffs.resize(2 * n + 1, E::Fr::zero());
+        w_coeffs[n + 1] -= v;
+
+        let low =
+            VariableBaseMSM::multi_scalar_mul(&ck.powers_of_beta_g_first, &scalars(&w_coeffs[..=n]));
+        let high = VariableBaseMSM::multi_scalar_mul(
+            &ck.powers_of_beta_g_second,
+            &scalars(&w_coeffs[n + 2..]),
+        );
+
+        low + high
+    }
+
+    /// `A(X) = sum_{i=1}^n a_i X^i`.
+    fn a_poly<F: ark_ff::Field>(a: &[F]) -> DensePolynomial<F> {
+        let mut coeffs = vec![F::zero(); a.len() + 1];
+        coeffs[1..].copy_from_slice(a);
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// `B(X) = sum_{j=1}^n b_j X^{n+1-j}`.
+    fn reversed_b_poly<F: ark_ff::Field>(b: &[F]) -> DensePolynomial<F> {
+        let n = b.len();
+        let mut coeffs = vec![F::zero(); n + 1];
+        for (idx, b_j) in b.iter().enumerate() {
+            coeffs[n - idx] = *b_j;
+        }
+        DensePolynomial::from_coefficients_vec(coeffs)
+    }
+
+    /// `b_j` in reverse order, matching the exponents of `powers_of_beta_h[1..=n]`.
+    fn reversed_scalars<F: PrimeField>(b: &[F]) -> Vec<F::BigInt> {
+        b.iter().rev().map(|s| s.into_repr()).collect()
+    }
+
+    /// `g_B = sum_j b_j * h^{beta^{n+1-j}}`, computed on the G2 side.
+    fn reversed_b_g2<E: PairingEngine>(ck: &CommitmentKey<E>, b: &[E::Fr]) -> E::G2Affine {
+        let bases = &ck.powers_of_beta_h[1..=b.len()];
+        let scalars = Self::reversed_scalars(b);
+        VariableBaseMSM::multi_scalar_mul(bases, &scalars).into()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use ark_bls12_381::{Bls12_381, Fr};
+    use ark_ec::AffineCurve;
+    use ark_ff::Field;
+    use ark_std::{test_rng, UniformRand};
+
+    use super::*;
+
+    /// A toy SRS for a chosen `beta` — not an MPC ceremony output, just enough structure
+    /// to exercise `ILV` against realistic-ish vector lengths in tests. `pub(crate)` so other
+    /// modules' tests can reuse it rather than hand-rolling their own.
+    pub(crate) fn toy_ck(dim: usize) -> CommitmentKey<Bls12_381> {
+        let rng = &mut test_rng();
+        let beta = Fr::rand(rng);
+        let g = <Bls12_381 as ark_ec::PairingEngine>::G1Affine::prime_subgroup_generator();
+        let h = <Bls12_381 as ark_ec::PairingEngine>::G2Affine::prime_subgroup_generator();
+        let pow = |i: usize| beta.pow([i as u64]);
+
+        CommitmentKey {
+            powers_of_beta_g_first: (0..=dim).map(|i| g.mul(pow(i)).into()).collect(),
+            powers_of_beta_g_second: (dim + 2..=2 * dim).map(|i| g.mul(pow(i)).into()).collect(),
+            powers_of_beta_h: (0..=dim + 1).map(|i| h.mul(pow(i)).into()).collect(),
+        }
+    }
+
+    #[test]
+    fn open_verify_round_trip() {
+        let dim = 8;
+        let ck = toy_ck(dim);
+        let rng = &mut test_rng();
+
+        let a: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let b: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let v = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+
+        let commitment = ILV::commit(&ck, &a);
+        let proof = ILV::open(&ck, &a, &b, v);
+        assert!(ILV::verify(&ck, &commitment, &b, v, &proof));
+    }
+
+    #[test]
+    fn verify_rejects_wrong_claim() {
+        let dim = 8;
+        let ck = toy_ck(dim);
+        let rng = &mut test_rng();
+
+        let a: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let b: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let v: Fr = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+
+        let commitment = ILV::commit(&ck, &a);
+        let proof = ILV::open(&ck, &a, &b, v);
+        assert!(!ILV::verify(&ck, &commitment, &b, v + Fr::from(1u64), &proof));
+    }
+
+    #[test]
+    fn commit_hiding_open_hiding_round_trip() {
+        let dim = 8;
+        let ck = toy_ck(dim);
+        let rng = &mut test_rng();
+
+        let a: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let b: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let v = a.iter().zip(&b).map(|(x, y)| *x * y).sum();
+
+        let hk = HidingKey::derive(&ck, rng);
+        let (commitment, r) = ILV::commit_hiding(&ck, &hk, &a, rng);
+        let proof = ILV::open_hiding(&ck, &hk, &a, &b, v, r);
+        assert!(ILV::verify(&ck, &commitment, &b, v, &proof));
+    }
+
+    #[test]
+    fn prove_batch_verify_batch_round_trip() {
+        let dim = 8;
+        let ck = toy_ck(dim);
+        let rng = &mut test_rng();
+
+        let a: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let b1: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let b2: Vec<Fr> = (0..dim).map(|_| Fr::rand(rng)).collect();
+        let v1: Fr = a.iter().zip(&b1).map(|(x, y)| *x * y).sum();
+        let v2: Fr = a.iter().zip(&b2).map(|(x, y)| *x * y).sum();
+
+        let commitment = ILV::commit(&ck, &a);
+        let claims: Vec<(&[Fr], Fr)> = vec![(&b1, v1), (&b2, v2)];
+        let proof = ILV::prove_batch(&ck, &a, &commitment, &claims);
+        assert!(ILV::verify_batch(&ck, &commitment, &claims, &proof));
+    }
+}
@@ -0,0 +1,41 @@
+use ark_ec::PairingEngine;
+use ark_std::UniformRand;
+
+use crate::{
+    algorithms::ILV,
+    data_structures::{Commitment, CommitmentKey, Proof},
+};
+
+/// A forged opening: a commitment to `a` together with a `claimed_inner_product` and a
+/// `proof` that verifies against an arbitrary public vector `b`, even though `a` does not
+/// actually have that inner product with it.
+///
+/// This forgery abuses a malformed SRS (a `powers_of_beta_g_first` leaking
+/// `g^{beta^{dim+1}}`), not a flaw in the inner-product relation `ILV::verify` checks.
+/// Given such a key, `ILV::verify` has no way to tell the forged proof apart from a real
+/// one — it is `CommitmentKey::validate` that rejects such a key; `main` shows it rejecting
+/// the shipped SRS before running this forgery against it.
+pub struct Attack<E: PairingEngine> {
+    pub a: Vec<E::Fr>,
+    pub commitment: Commitment<E>,
+    pub claimed_inner_product: E::Fr,
+    pub proof: Proof<E>,
+}
+
+impl<E: PairingEngine> Attack<E> {
+    /// Checks that the forged proof verifies against a freshly-sampled random public
+    /// vector `b` of length `dim`, demonstrating that the forgery isn't tied to any
+    /// particular choice of `b`.
+    pub fn assert_attack_works(&self, ck: &CommitmentKey<E>, dim: usize) {
+        let mut rng = ark_std::test_rng();
+        let b = (0..dim).map(|_| E::Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+        assert!(ILV::verify(
+            ck,
+            &self.commitment,
+            &b,
+            self.claimed_inner_product,
+            &self.proof,
+        ));
+    }
+}
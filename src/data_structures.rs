@@ -0,0 +1,167 @@
+use ark_ec::{AffineCurve, PairingEngine};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::UniformRand;
+
+/// The prover/verifier key for the [`ILV`](crate::algorithms::ILV) inner-product
+/// commitment scheme.
+///
+/// `powers_of_beta_g_first` holds `g^{beta^0}, .., g^{beta^dim}` in `E::G1Affine`, which is
+/// all a prover needs to commit to a vector of length `dim`. `powers_of_beta_h` holds the
+/// matching `h^{beta^i}` in `E::G2Affine`; unlike the G1 side, the verifier needs powers of
+/// `h` one step beyond `dim` (up to `h^{beta^{dim+1}}`) to fold in the public vector and the `beta^{n+1}` term during
+/// `verify` without ever handing the prover a higher G1 power than it is entitled to. See
+/// [`validate`](CommitmentKey::validate) for the structural checks this key should satisfy.
+///
+/// `powers_of_beta_g_second` holds `g^{beta^{dim+2}}, .., g^{beta^{2*dim}}` — the top half
+/// of the degree-`2*dim` witness polynomial `ILV::open` needs, picking back up one position
+/// *after* `beta^{dim+1}`. That gap is deliberate: `beta^{dim+1}` is the one power `verify`
+/// ever folds in on the G2 side (via `powers_of_beta_h`), and it must never also be
+/// reachable in G1 under any field, first or second, or a witness could be re-targeted at
+/// an arbitrary false inner product the way `attack` does.
+///
+/// This type is deserialized directly from the fixed `ck.srs` asset (see `main::SRS`), so its
+/// fields are exactly what that trusted setup produced — see [`HidingKey`] for the blinding
+/// material `ILV::commit_hiding`/`ILV::open_hiding` need, which is deliberately *not* a field
+/// here.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitmentKey<E: PairingEngine> {
+    pub powers_of_beta_g_first: Vec<E::G1Affine>,
+    pub powers_of_beta_g_second: Vec<E::G1Affine>,
+    pub powers_of_beta_h: Vec<E::G2Affine>,
+}
+
+/// Blinding material for the hiding variants [`ILV::commit_hiding`](crate::algorithms::ILV::commit_hiding)
+/// / [`ILV::open_hiding`](crate::algorithms::ILV::open_hiding).
+///
+/// Unlike [`CommitmentKey`], this is never serialized as part of `ck.srs`: `gamma` is a
+/// blinding trapdoor independent of `beta` that doesn't need to come from the same (or any)
+/// trusted setup, so [`derive`](Self::derive) samples a fresh one locally. It reuses
+/// `ck.powers_of_beta_g_first` — already `g^{beta^i}` for `i` in `0..=dim` — to build
+/// `powers_of_gamma_g[i] = g^{gamma * beta^i}` without ever needing `beta` itself.
+#[derive(Clone, Debug)]
+pub struct HidingKey<E: PairingEngine> {
+    pub gamma_g: E::G1Affine,
+    pub powers_of_gamma_g: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> HidingKey<E> {
+    /// Samples a fresh blinding trapdoor `gamma` and derives the matching powers from `ck`.
+    pub fn derive<R: ark_std::rand::Rng>(ck: &CommitmentKey<E>, rng: &mut R) -> Self {
+        let gamma = E::Fr::rand(rng);
+        HidingKey {
+            gamma_g: ck.powers_of_beta_g_first[0].mul(gamma).into(),
+            powers_of_gamma_g: ck
+                .powers_of_beta_g_first
+                .iter()
+                .map(|g| g.mul(gamma).into())
+                .collect(),
+        }
+    }
+}
+
+impl<E: PairingEngine> CommitmentKey<E> {
+    /// Audits this key for the structural issue that makes the forged-witness attack on
+    /// [`ILV`](crate::algorithms::ILV) possible: either half of the G1 powers carrying a
+    /// `g^{beta^{dim+1}}` term, the one power `ILV::witness` must never touch.
+    ///
+    /// Runs the same pairing checks the attack relies on, but in reverse: every consecutive
+    /// pair of G1 powers within each half must be consistent with `beta` via
+    /// `powers_of_beta_h`, `powers_of_beta_g_first` must stop at exactly `beta^{dim}`,
+    /// `powers_of_beta_g_second` must start at exactly `beta^{dim+2}` (checked by relating its
+    /// first entry back to `powers_of_beta_g_first`'s last one via `h^{beta^2}`), and both
+    /// halves must have the lengths `dim` requires. Together, these rule out a
+    /// `g^{beta^{dim+1}}` being smuggled in under any index, since any such entry would break
+    /// consistency with its neighbours.
+    ///
+    /// Intended as a cheap audit to run right after `deserialize_unchecked(SRS)`.
+    pub fn validate(&self, dim: usize) -> bool {
+        if self.powers_of_beta_g_first.len() != dim + 1 {
+            return false;
+        }
+        if self.powers_of_beta_g_second.len() != dim.saturating_sub(1) {
+            return false;
+        }
+
+        let h = self.powers_of_beta_h[0];
+        let beta_h = self.powers_of_beta_h[1];
+        let beta_sq_h = self.powers_of_beta_h[2];
+
+        let first_consistent = self
+            .powers_of_beta_g_first
+            .windows(2)
+            .all(|w| E::pairing(w[1], h) == E::pairing(w[0], beta_h));
+        let second_consistent = self
+            .powers_of_beta_g_second
+            .windows(2)
+            .all(|w| E::pairing(w[1], h) == E::pairing(w[0], beta_h));
+        let halves_linked = match self.powers_of_beta_g_second.first() {
+            Some(second_first) => {
+                E::pairing(*second_first, h)
+                    == E::pairing(self.powers_of_beta_g_first[dim], beta_sq_h)
+            }
+            None => true,
+        };
+
+        first_consistent && second_consistent && halves_linked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bls12_381::{Bls12_381, Fr};
+    use ark_ec::AffineCurve;
+    use ark_ff::Field;
+    use ark_std::{test_rng, UniformRand};
+
+    use super::*;
+
+    fn toy_ck_with_beta(dim: usize) -> (CommitmentKey<Bls12_381>, Fr) {
+        let rng = &mut test_rng();
+        let beta = Fr::rand(rng);
+        let g = <Bls12_381 as PairingEngine>::G1Affine::prime_subgroup_generator();
+        let h = <Bls12_381 as PairingEngine>::G2Affine::prime_subgroup_generator();
+        let pow = |i: usize| beta.pow([i as u64]);
+
+        let ck = CommitmentKey {
+            powers_of_beta_g_first: (0..=dim).map(|i| g.mul(pow(i)).into()).collect(),
+            powers_of_beta_g_second: (dim + 2..=2 * dim).map(|i| g.mul(pow(i)).into()).collect(),
+            powers_of_beta_h: (0..=dim + 1).map(|i| h.mul(pow(i)).into()).collect(),
+        };
+        (ck, beta)
+    }
+
+    fn toy_ck(dim: usize) -> CommitmentKey<Bls12_381> {
+        toy_ck_with_beta(dim).0
+    }
+
+    #[test]
+    fn validate_accepts_well_formed_key() {
+        assert!(toy_ck(8).validate(8));
+    }
+
+    #[test]
+    fn validate_rejects_leaked_power() {
+        let dim = 8;
+        let (mut ck, beta) = toy_ck_with_beta(dim);
+        let g = <Bls12_381 as PairingEngine>::G1Affine::prime_subgroup_generator();
+        // Mimic the shipped malformed SRS: an extra `g^{beta^{dim+1}}` appended to the end of
+        // `powers_of_beta_g_first`, the exact leak `attack` exploits.
+        ck.powers_of_beta_g_first
+            .push(g.mul(beta.pow([dim as u64 + 1])).into());
+        assert!(!ck.validate(dim));
+    }
+
+    #[test]
+    fn validate_rejects_wrong_dimension() {
+        assert!(!toy_ck(8).validate(9));
+    }
+}
+
+/// A commitment to a vector `a`, i.e. `C = g^{A(beta)}`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<E: PairingEngine>(pub E::G1Affine);
+
+/// A proof that the vector committed to in a [`Commitment`] has a claimed inner product
+/// with some public vector `b`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: PairingEngine>(pub E::G1Affine);
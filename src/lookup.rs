@@ -0,0 +1,206 @@
+//! A Caulk+-style lookup subsystem built on top of the [`ILV`](crate::algorithms::ILV)
+//! commitment: prove that every entry of a committed vector `a` lies in a public table `T`,
+//! without revealing which table row each entry maps to.
+//!
+//! A table is represented by its vanishing polynomial `Z_T(X) = prod_j (X - t_j)`, which is
+//! committed once and reused across every proof against it (the "precompute phase" — see
+//! [`Table::precompute`]). A proof then exhibits the vanishing polynomial `Z_a(X) = prod_i
+//! (X - a_i)` of the values actually used, committed on the G2 side so that individual
+//! entries and the mapping to table rows both stay hidden, together with the quotient `Q(X)
+//! = Z_T(X) / Z_a(X)`. Since `Z_T` vanishes exactly on the table, every root of `Z_a` — i.e.
+//! every entry of `a` — must be a table row, which the verifier checks with one pairing.
+//!
+//! A [`LookupProof`] is tied to the specific [`Commitment`] it was built from: `prove` takes
+//! it alongside the plaintext `a` and asserts they match, and stores it; `verify` then takes
+//! the commitment the caller actually wants this proof checked against and requires it to
+//! equal the stored one before trusting the pairing check at all.
+
+use ark_ec::{msm::VariableBaseMSM, PairingEngine};
+use ark_ff::{Field, PrimeField};
+use ark_poly::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    UVPolynomial,
+};
+
+use crate::{
+    algorithms::ILV,
+    data_structures::{Commitment, CommitmentKey},
+};
+
+/// A public lookup table, committed once via its vanishing polynomial and reused across
+/// every membership proof against it.
+pub struct Table<E: PairingEngine> {
+    pub rows: Vec<E::Fr>,
+    vanishing_poly: DensePolynomial<E::Fr>,
+    /// `g^{Z_T(beta)}`.
+    pub commitment: E::G1Affine,
+}
+
+impl<E: PairingEngine> Table<E> {
+    /// Precompute phase: builds and commits `Z_T(X)`. Run once per table, independently of
+    /// any vector that will later be proven to use it.
+    pub fn precompute(ck: &CommitmentKey<E>, rows: Vec<E::Fr>) -> Self {
+        let vanishing_poly = vanishing_polynomial(&rows);
+        let commitment = commit_g1(ck, &vanishing_poly);
+        Table {
+            rows,
+            vanishing_poly,
+            commitment,
+        }
+    }
+}
+
+/// A proof that every entry of the vector committed to in a specific [`Commitment`] appears
+/// somewhere in a [`Table`].
+pub struct LookupProof<E: PairingEngine> {
+    /// The commitment this proof is an opening for; `verify` requires the caller's
+    /// commitment to match this before trusting anything else about the proof.
+    commitment: Commitment<E>,
+    /// `h^{Z_a(beta)}`, a commitment to the vanishing polynomial of the values actually
+    /// used. Committing this on G2 rather than revealing the values or their table indices
+    /// is what keeps the mapping from `a` to table rows hidden.
+    used_commitment: E::G2Affine,
+    /// `g^{Q(beta)}` for `Q(X) = Z_T(X) / Z_a(X)`.
+    quotient_commitment: E::G1Affine,
+}
+
+impl<E: PairingEngine> LookupProof<E> {
+    /// Per-proof phase: proves that every entry of the vector `a` committed to in
+    /// `commitment` lies in `table`.
+    ///
+    /// Returns `None` if some entry of `a` is not in the table: in that case `Z_a` does not
+    /// divide `Z_T` and no valid quotient exists.
+    pub fn prove(
+        ck: &CommitmentKey<E>,
+        table: &Table<E>,
+        commitment: &Commitment<E>,
+        a: &[E::Fr],
+    ) -> Option<Self> {
+        assert_eq!(
+            commitment.0,
+            ILV::commit(ck, a).0,
+            "commitment must be ILV::commit(ck, a) for the same a this proof is built from"
+        );
+
+        // `Z_a` must have each *distinct* value used in `a` as a single root: a value repeated
+        // in `a` is still just one root of `Z_T`, so giving it multiplicity here would make
+        // `Z_a` fail to divide `Z_T` even though every entry of `a` is genuinely in the table.
+        let used_poly = vanishing_polynomial(&distinct(a));
+        let num = DenseOrSparsePolynomial::from(&table.vanishing_poly);
+        let den = DenseOrSparsePolynomial::from(&used_poly);
+        let (quotient, remainder) = num.divide_with_q_and_r(&den)?;
+        if !remainder.is_zero() {
+            return None;
+        }
+
+        Some(LookupProof {
+            commitment: *commitment,
+            used_commitment: commit_g2(ck, &used_poly),
+            quotient_commitment: commit_g1(ck, &quotient),
+        })
+    }
+
+    /// Verifies the proof against `table`'s precomputed commitment and the `commitment` it is
+    /// claimed to be an opening for.
+    ///
+    /// Checks that `commitment` matches the one `prove` built this proof from, then that
+    /// `e(Z_T, h) == e(Q, Z_a)`, i.e. that `Z_T = Z_a * Q` holds at `beta`.
+    pub fn verify(&self, ck: &CommitmentKey<E>, table: &Table<E>, commitment: &Commitment<E>) -> bool {
+        self.commitment == *commitment
+            && E::pairing(table.commitment, ck.powers_of_beta_h[0])
+                == E::pairing(self.quotient_commitment, self.used_commitment)
+    }
+}
+
+/// The distinct values among `values`, in first-occurrence order.
+///
+/// `E::Fr` doesn't implement `Hash`/`Ord`, so this is a plain linear scan rather than a
+/// `HashSet`/`BTreeSet` dedupe; fine at the vector lengths this crate targets.
+fn distinct<F: Field>(values: &[F]) -> Vec<F> {
+    let mut out: Vec<F> = Vec::new();
+    for v in values {
+        if !out.contains(v) {
+            out.push(*v);
+        }
+    }
+    out
+}
+
+/// `Z(X) = prod_i (X - values_i)`.
+fn vanishing_polynomial<F: Field>(values: &[F]) -> DensePolynomial<F> {
+    values.iter().fold(
+        DensePolynomial::from_coefficients_vec(vec![F::one()]),
+        |acc, v| &acc * &DensePolynomial::from_coefficients_vec(vec![-*v, F::one()]),
+    )
+}
+
+fn commit_g1<E: PairingEngine>(ck: &CommitmentKey<E>, poly: &DensePolynomial<E::Fr>) -> E::G1Affine {
+    let bases = &ck.powers_of_beta_g_first[..poly.coeffs.len()];
+    let scalars = poly.coeffs.iter().map(|c| c.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars).into()
+}
+
+fn commit_g2<E: PairingEngine>(ck: &CommitmentKey<E>, poly: &DensePolynomial<E::Fr>) -> E::G2Affine {
+    let bases = &ck.powers_of_beta_h[..poly.coeffs.len()];
+    let scalars = poly.coeffs.iter().map(|c| c.into_repr()).collect::<Vec<_>>();
+    VariableBaseMSM::multi_scalar_mul(bases, &scalars).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bls12_381::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    use super::*;
+    use crate::algorithms::tests::toy_ck;
+
+    #[test]
+    fn prove_verify_round_trip() {
+        let ck = toy_ck(16);
+        let rng = &mut test_rng();
+        let rows: Vec<Fr> = (0..10).map(|_| Fr::rand(rng)).collect();
+        let table = Table::precompute(&ck, rows.clone());
+
+        let a = vec![rows[2], rows[5], rows[2]];
+        let commitment = ILV::commit(&ck, &a);
+        let proof = LookupProof::prove(&ck, &table, &commitment, &a)
+            .expect("every entry of a is in the table");
+        assert!(proof.verify(&ck, &table, &commitment));
+    }
+
+    #[test]
+    fn prove_rejects_value_not_in_table() {
+        let ck = toy_ck(16);
+        let rng = &mut test_rng();
+        let rows: Vec<Fr> = (0..10).map(|_| Fr::rand(rng)).collect();
+        let table = Table::precompute(&ck, rows.clone());
+
+        let not_in_table = Fr::rand(rng);
+        let a = vec![rows[0], not_in_table];
+        let commitment = ILV::commit(&ck, &a);
+        assert!(LookupProof::prove(&ck, &table, &commitment, &a).is_none());
+    }
+
+    #[test]
+    fn verify_rejects_mismatched_commitment() {
+        let ck = toy_ck(16);
+        let rng = &mut test_rng();
+        let rows: Vec<Fr> = (0..10).map(|_| Fr::rand(rng)).collect();
+        let table = Table::precompute(&ck, rows.clone());
+
+        let a = vec![rows[2], rows[5]];
+        let commitment = ILV::commit(&ck, &a);
+        let proof =
+            LookupProof::prove(&ck, &table, &commitment, &a).expect("a is in the table");
+
+        let other = ILV::commit(&ck, &[rows[3]]);
+        assert!(!proof.verify(&ck, &table, &other));
+    }
+
+    #[test]
+    fn distinct_dedupes_preserving_order() {
+        let rng = &mut test_rng();
+        let (x, y): (Fr, Fr) = (Fr::rand(rng), Fr::rand(rng));
+        assert_eq!(distinct(&[x, y, x, x, y]), vec![x, y]);
+    }
+}
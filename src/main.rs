@@ -10,11 +10,22 @@ use data_structures::*;
 
 pub mod attack;
 
+pub mod lookup;
+
 fn main() {
     welcome();
     puzzle(PUZZLE_DESCRIPTION);
     // Supports committing to vectors of length up to 512.
     let ck = data_structures::CommitmentKey::<Bls12_381>::deserialize_unchecked(SRS).unwrap();
+
+    // `validate` is the fix: it detects the leaked `g^{beta^{dim+1}}` term in this key, which
+    // `ILV::verify` has no way to detect on its own. The attack below deliberately goes on to
+    // use the rejected key anyway, showing the forgery that skipping this check would allow.
+    assert!(
+        !ck.validate(SUPPORTED_DIM),
+        "validate() should have rejected this SRS: it leaks a G1 power beyond SUPPORTED_DIM"
+    );
+
     let attack = attack(&ck, SUPPORTED_DIM);
     attack.assert_attack_works(&ck, SUPPORTED_DIM);
 }
@@ -89,3 +100,17 @@ any details about the break. Can you help Bob figure out the issue, and fix his
 [1]: https://ia.cr/2022/406
 [2]: http://www.lsv.fr/Publis/PAPERS/PDF/ILV-imacc11-long.pdf
 ";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads the actual shipped `ck.srs` asset through `deserialize_unchecked`, so a
+    /// `CommitmentKey` field that doesn't match the wire format fails here instead of
+    /// panicking `main()`'s `.unwrap()` at startup, and checks that `validate` rejects it.
+    #[test]
+    fn srs_deserializes_and_is_rejected_by_validate() {
+        let ck = CommitmentKey::<Bls12_381>::deserialize_unchecked(SRS).unwrap();
+        assert!(!ck.validate(SUPPORTED_DIM));
+    }
+}